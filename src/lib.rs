@@ -9,18 +9,94 @@ use openai_flows::{
     chat::{ChatModel, ChatOptions, ChatRole, chat_history},
     OpenAIFlows,
 };
+use std::collections::HashSet;
 use store_flows::{get, set};
 use vector_store_flows::*;
 use flowsnet_platform_sdk::logger;
+use once_cell::sync::Lazy;
+use tiktoken_rs::{cl100k_base, CoreBPE};
+use sha2::{Digest, Sha256};
+use serde::{Deserialize, Serialize};
 
-// static SOFT_CHAR_LIMIT : usize = 20000; // GPT4 8k
-static SOFT_CHAR_LIMIT : usize = 30000; // GPT35 16k
+static DEFAULT_SCORE_THRESHOLD: f32 = 0.75;
+static DEFAULT_TOP_K: u64 = 5;
+
+// MMR reranking: how large a candidate pool to pull before reranking, and the
+// relevance/diversity tradeoff (1.0 = pure relevance, 0.0 = pure diversity).
+static MMR_CANDIDATE_POOL: u64 = 20;
+static MMR_LAMBDA: f32 = 0.5;
+
+// Chunking parameters for attachment ingestion.
+static INGEST_CHUNK_CHARS: usize = 1000;
+static INGEST_CHUNK_OVERLAP: usize = 200;
+
+// How many rounds of tool calls we'll follow before forcing a final answer.
+static MAX_TOOL_ITERATIONS: usize = 5;
+
+// Appended to the system prompt so the model can request another knowledge-base lookup
+// through plain text, since `chat_completion` has no native function-calling support.
+static SEARCH_DIRECTIVE: &str = "\n\nIf you need another knowledge-base lookup to answer, reply with EXACTLY `SEARCH: <query>` and nothing else. Otherwise answer the question normally.";
+
+// Reserve room for the model's reply when packing retrieved chunks into the system prompt.
+// Only the base system prompt and those chunks count against the budget; the question, the
+// chat history and the results of later model-requested searches are not counted.
+static RESERVED_REPLY_TOKENS: usize = 512;
+
+// Discord's hard cap on an embed field's `value` length, and how many sources we'll cite
+// before truncating so the "Sources" field never exceeds it.
+static DISCORD_FIELD_VALUE_LIMIT: usize = 1024;
+static MAX_CITED_SOURCES: usize = 5;
+
+static TOKENIZER: Lazy<CoreBPE> = Lazy::new(|| cl100k_base().expect("failed to load cl100k_base encoder"));
+
+fn count_tokens(s: &str) -> usize {
+    TOKENIZER.encode_with_special_tokens(s).len()
+}
+
+// Context window sizes, in tokens, for the chat models this bot can be configured with.
+fn context_window(model: &ChatModel) -> usize {
+    match model {
+        ChatModel::GPT35Turbo16K => 16384,
+        ChatModel::GPT4 => 8192,
+        _ => 4096,
+    }
+}
 
 #[derive(Debug)]
 struct ContentSettings {
     system_prompt: String,
     error_mesg: String,
     collection_name: String,
+    score_threshold: f32,
+    top_k: u64,
+}
+
+// Per-channel overrides of `ContentSettings`, persisted through `store_flows` so one
+// deployed bot can serve multiple channels each bound to a different persona/collection.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ChannelOverrides {
+    system_prompt: Option<String>,
+    collection_name: Option<String>,
+    score_threshold: Option<f32>,
+    top_k: Option<u64>,
+}
+
+fn overrides_key(channel_id: &str) -> String {
+    format!("{}:settings", channel_id)
+}
+
+fn load_overrides(channel_id: &str) -> ChannelOverrides {
+    get(&overrides_key(channel_id))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+// A retrieved chunk that ended up in the prompt, kept around so the final reply can cite it.
+#[derive(Debug)]
+struct Source {
+    score: f32,
+    excerpt: String,
+    url: Option<String>,
 }
 
 #[no_mangle]
@@ -38,10 +114,14 @@ async fn handler(msg: Message) {
     let bot = ProvidedBot::new(discord_token);
 
     let bot_id = std::env::var("bot_id").unwrap().parse::<u64>().unwrap();
+    let channel_id = msg.channel_id;
+    let overrides = load_overrides(&channel_id.to_string());
     let cs = &ContentSettings {
-        system_prompt: std::env::var("system_prompt").unwrap_or("".to_string()),
+        system_prompt: overrides.system_prompt.unwrap_or_else(|| std::env::var("system_prompt").unwrap_or("".to_string())),
         error_mesg: std::env::var("error_mesg").unwrap_or("".to_string()),
-        collection_name: std::env::var("collection_name").unwrap_or("".to_string()),
+        collection_name: overrides.collection_name.unwrap_or_else(|| std::env::var("collection_name").unwrap_or("".to_string())),
+        score_threshold: overrides.score_threshold.unwrap_or(DEFAULT_SCORE_THRESHOLD),
+        top_k: overrides.top_k.unwrap_or(DEFAULT_TOP_K),
     };
     log::info!("The system prompt is {} lines", cs.system_prompt.lines().count());
 
@@ -65,10 +145,30 @@ async fn handler(msg: Message) {
         }
     }
 
-    let channel_id = msg.channel_id;
     log::info!("Received message from {}", channel_id);
 
+    if !msg.attachments.is_empty() {
+        let mut openai = OpenAIFlows::new();
+        openai.set_retry_times(3);
+        let (ingested, skipped) = ingest_attachments(&msg, &cs.collection_name, &openai).await;
+        _ = discord.send_message(
+            channel_id.into(),
+            &serde_json::json!({
+                "content": format!("Ingested {} chunk(s) into `{}` ({} duplicate(s) skipped).", ingested, cs.collection_name, skipped)
+            }),
+        ).await;
+        return;
+    }
+
     let mut text = String::from(&msg.content);
+    if text.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("/set ")) {
+        let reply = apply_channel_setting(&channel_id.to_string(), &text[5..]);
+        _ = discord.send_message(
+            channel_id.into(),
+            &serde_json::json!({ "content": reply }),
+        ).await;
+        return;
+    }
     if text.eq_ignore_ascii_case("/new") {
         _ = discord.send_message(
             channel_id.into(),
@@ -140,21 +240,36 @@ async fn handler(msg: Message) {
         }
     };
 
-    // Search for embeddings from the question
+    // Search for embeddings from the question, over-fetching so MMR has a pool to rerank.
     let p = PointsSearchParams {
-        vector: question_vector,
-        limit: 5,
+        vector: question_vector.clone(),
+        limit: cs.top_k.max(MMR_CANDIDATE_POOL),
     };
+    // model: ChatModel::GPT4,
+    let chat_model = ChatModel::GPT35Turbo16K;
     let mut system_prompt_updated = String::from(&cs.system_prompt);
+    let token_budget = context_window(&chat_model).saturating_sub(RESERVED_REPLY_TOKENS);
+    let mut used_tokens = count_tokens(&system_prompt_updated);
+    let mut sources: Vec<Source> = Vec::new();
     match search_points(&cs.collection_name, &p).await {
         Ok(sp) => {
-            for p in sp.iter() {
-                if system_prompt_updated.len() > SOFT_CHAR_LIMIT { break; }
-                log::debug!("Received vector score={} and text={}", p.score, first_x_chars(p.payload.as_ref().unwrap().get("text").unwrap().as_str().unwrap(), 256));
-                if p.score > 0.75 {
-                    system_prompt_updated.push_str("\n");
-                    system_prompt_updated.push_str(p.payload.as_ref().unwrap().get("text").unwrap().as_str().unwrap());
-                }
+            let candidates: Vec<_> = sp.into_iter().filter(|p| p.score > cs.score_threshold).collect();
+            let reranked = mmr_rerank(&question_vector, candidates, cs.top_k as usize);
+            for p in reranked.iter() {
+                if used_tokens >= token_budget { break; }
+                let payload = p.payload.as_ref().unwrap();
+                let chunk_text = payload.get("text").unwrap().as_str().unwrap();
+                log::debug!("Selected vector score={} and text={}", p.score, first_x_chars(chunk_text, 256));
+                let chunk_tokens = count_tokens(chunk_text);
+                if used_tokens + chunk_tokens > token_budget { break; }
+                system_prompt_updated.push_str("\n");
+                system_prompt_updated.push_str(chunk_text);
+                used_tokens += chunk_tokens;
+                sources.push(Source {
+                    score: p.score,
+                    excerpt: first_x_chars(chunk_text, 100),
+                    url: payload.get("url").and_then(|v| v.as_str()).map(String::from),
+                });
             }
         }
         Err(e) => {
@@ -184,36 +299,61 @@ async fn handler(msg: Message) {
         _ => (),
     }
 
-    let co = ChatOptions {
-        // model: ChatModel::GPT4,
-        model: ChatModel::GPT35Turbo16K,
-        restart: restart,
-        system_prompt: Some(&system_prompt_updated),
+    // No native function calling, so SEARCH_DIRECTIVE drives a text-based substitute; the
+    // back-and-forth runs on a scratch id so it doesn't pollute channel_id's chat_history.
+    let system_prompt_with_directive = format!("{}{}", system_prompt_updated, SEARCH_DIRECTIVE);
+    let scratch_id = format!("{}:tool:{}", channel_id, msg.id);
+    let scratch_co = ChatOptions {
+        model: chat_model,
+        restart: true,
+        system_prompt: Some(&system_prompt_with_directive),
         ..Default::default()
     };
 
-    match openai.chat_completion(&channel_id.to_string(), &text, &co).await {
-        Ok(r) => {
-            let resps = sub_strings(&r.choice, 1800);
-
-            _ = discord.edit_message(
-                channel_id.into(), placeholder.id.into(),
-                &serde_json::json!({
-                    "content": resps[0]
-                }),
-            ).await;
+    let mut next_input = text.clone();
+    let mut gathered_context = String::new();
+    for _ in 0..MAX_TOOL_ITERATIONS {
+        let r = match openai.chat_completion(&scratch_id, &next_input, &scratch_co).await {
+            Ok(r) => r,
+            Err(e) => {
+                _ = discord.edit_message(
+                    channel_id.into(), placeholder.id.into(),
+                    &serde_json::json!({
+                        "content": &cs.error_mesg
+                    }),
+                ).await;
+                log::error!("OpenAI returns error: {}", e);
+                return;
+            }
+        };
 
-            if resps.len() > 1 {
-                for resp in resps.iter().skip(1) {
-                    _  = discord.send_message(
-                        channel_id.into(),
-                        &serde_json::json!({
-                            "content": resp
-                        }),
-                    ).await;
-                }
+        match r.choice.trim().strip_prefix("SEARCH:") {
+            Some(query) => {
+                let query = query.trim();
+                log::info!("Model requested another search for '{}'", query);
+                let results = run_knowledge_base_search(&mut openai, &cs.collection_name, query, &mut sources).await;
+                gathered_context.push_str(&format!("\nKnowledge base results for \"{}\":\n{}\n", query, results));
+                next_input = format!(
+                    "Knowledge base results for \"{}\":\n{}\n\nUsing that (and anything already known), answer the original question: {}",
+                    query, results, text
+                );
             }
+            None => break,
         }
+    }
+
+    if !gathered_context.is_empty() {
+        system_prompt_updated.push_str(&gathered_context);
+    }
+
+    let co = ChatOptions {
+        model: chat_model,
+        restart: restart,
+        system_prompt: Some(&system_prompt_updated),
+        ..Default::default()
+    };
+    let final_choice = match openai.chat_completion(&channel_id.to_string(), &text, &co).await {
+        Ok(r) => r.choice,
         Err(e) => {
             _ = discord.edit_message(
                 channel_id.into(), placeholder.id.into(),
@@ -224,6 +364,37 @@ async fn handler(msg: Message) {
             log::error!("OpenAI returns error: {}", e);
             return;
         }
+    };
+
+    if final_choice.is_empty() {
+        log::error!("Got an empty final answer");
+        _ = discord.edit_message(
+            channel_id.into(), placeholder.id.into(),
+            &serde_json::json!({
+                "content": &cs.error_mesg
+            }),
+        ).await;
+        return;
+    }
+
+    let resps = sub_strings(&final_choice, 1800);
+
+    _ = discord.edit_message(
+        channel_id.into(), placeholder.id.into(),
+        &serde_json::json!({
+            "embeds": [source_embed(resps[0], &sources)]
+        }),
+    ).await;
+
+    if resps.len() > 1 {
+        for resp in resps.iter().skip(1) {
+            _  = discord.send_message(
+                channel_id.into(),
+                &serde_json::json!({
+                    "content": resp
+                }),
+            ).await;
+        }
     }
 
     // A successful restart. The new message will NOT be a restart
@@ -237,6 +408,169 @@ fn first_x_chars(s: &str, x: usize) -> String {
     s.chars().take(x).collect()
 }
 
+// Handles `/set collection|prompt|threshold|topk <value>`, persisting the new value as a
+// per-channel override. Returns the confirmation (or error) message to send back.
+fn apply_channel_setting(channel_id: &str, rest: &str) -> String {
+    let mut parts = rest.splitn(2, ' ');
+    let key = parts.next().unwrap_or_default().to_lowercase();
+    let value = parts.next().unwrap_or_default().trim();
+    if value.is_empty() {
+        return format!("Usage: `/set {} <value>`", key);
+    }
+
+    let mut overrides = load_overrides(channel_id);
+    let reply = match key.as_str() {
+        "collection" => {
+            overrides.collection_name = Some(value.to_string());
+            format!("Collection set to `{}`.", value)
+        }
+        "prompt" => {
+            overrides.system_prompt = Some(value.to_string());
+            "System prompt updated.".to_string()
+        }
+        "threshold" => match value.parse::<f32>() {
+            Ok(v) => {
+                overrides.score_threshold = Some(v);
+                format!("Score threshold set to {}.", v)
+            }
+            Err(_) => return format!("`{}` is not a valid threshold.", value),
+        },
+        "topk" => match value.parse::<u64>() {
+            Ok(v) => {
+                overrides.top_k = Some(v);
+                format!("Top-k set to {}.", v)
+            }
+            Err(_) => return format!("`{}` is not a valid top-k.", value),
+        },
+        _ => return format!("Unknown setting `{}`. Use collection, prompt, threshold, or topk.", key),
+    };
+
+    set(&overrides_key(channel_id), json!(overrides), None);
+    reply
+}
+
+// Greedily selects up to `max_count` candidates that maximize relevance to the query while
+// penalizing redundancy with chunks already selected (Maximal Marginal Relevance).
+fn mmr_rerank(query_vector: &[f32], mut candidates: Vec<PointsSearchResult>, max_count: usize) -> Vec<PointsSearchResult> {
+    let mut selected: Vec<PointsSearchResult> = Vec::new();
+    while !candidates.is_empty() && selected.len() < max_count {
+        let best = candidates
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (i, mmr_score(query_vector, c, &selected)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+            .unwrap();
+        selected.push(candidates.remove(best));
+    }
+    selected
+}
+
+// Search results carry no vector, only payload/score, so MMR reads it back out of the
+// `embedding` field ingestion stashes in the payload (doubling stored vectors per point).
+fn candidate_vector(candidate: &PointsSearchResult) -> Option<Vec<f32>> {
+    candidate
+        .payload
+        .as_ref()?
+        .get("embedding")?
+        .as_array()?
+        .iter()
+        .map(|v| v.as_f64().map(|f| f as f32))
+        .collect()
+}
+
+fn mmr_score(query_vector: &[f32], candidate: &PointsSearchResult, selected: &[PointsSearchResult]) -> f32 {
+    let candidate_vector = candidate_vector(candidate);
+    let relevance = candidate_vector
+        .as_ref()
+        .map(|v| cosine_similarity(query_vector, v))
+        .unwrap_or(candidate.score);
+    let redundancy = candidate_vector
+        .as_ref()
+        .map(|cv| {
+            selected
+                .iter()
+                .filter_map(|s| candidate_vector(s).map(|sv| cosine_similarity(cv, &sv)))
+                .fold(0.0_f32, f32::max)
+        })
+        .unwrap_or(0.0);
+    MMR_LAMBDA * relevance - (1.0 - MMR_LAMBDA) * redundancy
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+// Builds a Discord embed with `answer` as the description and, when present, a "Sources"
+// field citing the retrieved chunks that made it into the prompt.
+fn source_embed(answer: &str, sources: &[Source]) -> serde_json::Value {
+    let mut embed = json!({ "description": answer });
+    if !sources.is_empty() {
+        let value = sources
+            .iter()
+            .take(MAX_CITED_SOURCES)
+            .map(|s| match &s.url {
+                Some(url) => format!("[{:.2}] {} ({})", s.score, s.excerpt, url),
+                None => format!("[{:.2}] {}", s.score, s.excerpt),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        // Discord caps an embed field's `value` at 1024 chars; past that the whole
+        // edit_message call silently fails and the placeholder is never replaced.
+        let value = if value.chars().count() > DISCORD_FIELD_VALUE_LIMIT {
+            format!("{}...", first_x_chars(&value, DISCORD_FIELD_VALUE_LIMIT - 3))
+        } else {
+            value
+        };
+        embed["fields"] = json!([{ "name": "Sources", "value": value, "inline": false }]);
+    }
+    embed
+}
+
+// Runs one model-requested search and cites the hits in `sources` too, since they can
+// shape the final answer as much as the initial search's hits.
+async fn run_knowledge_base_search(openai: &mut OpenAIFlows, collection_name: &str, query: &str, sources: &mut Vec<Source>) -> String {
+    let query_vector = match openai.create_embeddings(EmbeddingsInput::String(query.to_string())).await {
+        Ok(r) if !r.is_empty() => r[0].iter().map(|n| *n as f32).collect(),
+        _ => {
+            log::error!("Failed to embed tool-call query '{}'", query);
+            return "No results: failed to embed the query.".to_string();
+        }
+    };
+
+    let p = PointsSearchParams { vector: query_vector, limit: DEFAULT_TOP_K };
+    match search_points(collection_name, &p).await {
+        Ok(sp) => {
+            let mut formatted = String::new();
+            for hit in sp.iter() {
+                let text = hit.payload.as_ref().and_then(|p| p.get("text")).and_then(|v| v.as_str()).unwrap_or_default();
+                formatted.push_str(&format!("- (score {:.3}) {}\n", hit.score, first_x_chars(text, 500)));
+                sources.push(Source {
+                    score: hit.score,
+                    excerpt: first_x_chars(text, 100),
+                    url: hit.payload.as_ref().and_then(|p| p.get("url")).and_then(|v| v.as_str()).map(String::from),
+                });
+            }
+            if formatted.is_empty() {
+                "No results found for that query.".to_string()
+            } else {
+                formatted
+            }
+        }
+        Err(e) => {
+            log::error!("Vector search for tool call returned error: {}", e);
+            "No results: the knowledge base search failed.".to_string()
+        }
+    }
+}
+
 fn sub_strings(string: &str, sub_len: usize) -> Vec<&str> {
     let mut subs = Vec::with_capacity(string.len() / sub_len);
     let mut iter = string.chars();
@@ -252,3 +586,124 @@ fn sub_strings(string: &str, sub_len: usize) -> Vec<&str> {
     }
     subs
 }
+
+// Downloads each text attachment on `msg`, splits it into overlapping chunks, embeds the
+// unseen ones, and upserts them into `collection_name`. Returns (ingested, skipped_duplicates).
+async fn ingest_attachments(msg: &Message, collection_name: &str, openai: &OpenAIFlows) -> (usize, usize) {
+    let mut ingested = 0usize;
+    let mut skipped = 0usize;
+    let mut seen_hashes = load_seen_hashes(collection_name);
+
+    for attachment in &msg.attachments {
+        let guess = mime_guess::from_path(&attachment.filename).first_or_octet_stream();
+        if guess.type_() != mime_guess::mime::TEXT {
+            log::info!("Skipping attachment {} with non-text mime type {}", attachment.filename, guess);
+            continue;
+        }
+
+        // A small blocking fetch (no async runtime of its own) so we don't pull in a full
+        // HTTP client the flows runtime may not support for outbound requests.
+        let mut body_bytes: Vec<u8> = Vec::new();
+        if let Err(e) = http_req::request::get(&attachment.url, &mut body_bytes) {
+            log::error!("Failed to download attachment {}: {}", attachment.filename, e);
+            continue;
+        }
+        let body = String::from_utf8_lossy(&body_bytes).to_string();
+
+        for chunk in chunk_text(&body, INGEST_CHUNK_CHARS, INGEST_CHUNK_OVERLAP) {
+            let hash = hash_text(&chunk);
+
+            // Check the seen-hash set before embedding, not after, so a duplicate chunk
+            // never costs an embedding call in the first place.
+            if seen_hashes.contains(&hash) {
+                log::debug!("Skipping duplicate chunk with hash {}", hash);
+                skipped += 1;
+                continue;
+            }
+
+            let vector: Vec<f32> = match openai.create_embeddings(EmbeddingsInput::String(chunk.clone())).await {
+                Ok(r) if !r.is_empty() => r[0].iter().map(|n| *n as f32).collect(),
+                Ok(_) => {
+                    log::error!("OpenAI returned no embedding for an ingested chunk");
+                    continue;
+                }
+                Err(e) => {
+                    log::error!("OpenAI returned an error while embedding an ingested chunk: {}", e);
+                    continue;
+                }
+            };
+
+            let point = Point {
+                id: point_id_from_hash(&hash),
+                vector: vector.clone(),
+                payload: Some(json!({
+                    "text": chunk,
+                    "hash": hash,
+                    "source": attachment.filename,
+                    // Search results only carry the payload, not the stored vector, so MMR
+                    // reranking reads the embedding back out of here.
+                    "embedding": vector,
+                })),
+            };
+
+            if let Err(e) = upsert_points(collection_name, vec![point]).await {
+                log::error!("Failed to upsert ingested chunk: {}", e);
+                continue;
+            }
+            seen_hashes.insert(hash);
+            ingested += 1;
+        }
+    }
+
+    save_seen_hashes(collection_name, &seen_hashes);
+    (ingested, skipped)
+}
+
+fn seen_hashes_key(collection_name: &str) -> String {
+    format!("{}:seen-hashes", collection_name)
+}
+
+fn load_seen_hashes(collection_name: &str) -> HashSet<String> {
+    get(&seen_hashes_key(collection_name))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn save_seen_hashes(collection_name: &str, hashes: &HashSet<String>) {
+    set(&seen_hashes_key(collection_name), json!(hashes), None);
+}
+
+// Splits `text` into `chunk_chars`-sized (char count) windows that overlap by `overlap_chars`.
+fn chunk_text(text: &str, chunk_chars: usize, overlap_chars: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.is_empty() {
+        return Vec::new();
+    }
+    let step = chunk_chars.saturating_sub(overlap_chars).max(1);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let end = (start + chunk_chars).min(chars.len());
+        chunks.push(chars[start..end].iter().collect());
+        if end == chars.len() { break; }
+        start += step;
+    }
+    chunks
+}
+
+// Collapses whitespace and case so near-identical re-uploads hash the same.
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn hash_text(text: &str) -> String {
+    format!("{:x}", Sha256::digest(normalize_text(text).as_bytes()))
+}
+
+// Derives a stable numeric point ID from a content hash so re-ingesting the same chunk
+// is naturally idempotent even if the seen-hash check in `ingest_attachments` races with
+// another ingestion.
+fn point_id_from_hash(hash: &str) -> PointId {
+    PointId::Num(u64::from_str_radix(&hash[..16], 16).unwrap_or_default())
+}
+